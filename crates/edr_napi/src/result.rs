@@ -75,6 +75,102 @@ pub struct RevertResult {
     pub gas_used: BigInt,
     /// The transaction output
     pub output: JsBuffer,
+    /// The decoded reason string, if `output` is an ABI-encoded
+    /// `Error(string)`
+    pub reason: Option<String>,
+    /// The decoded panic code, if `output` is an ABI-encoded `Panic(uint256)`
+    pub panic_code: Option<BigInt>,
+}
+
+/// The function selector of the builtin `Error(string)` revert reason.
+const ERROR_SELECTOR: [u8; 4] = [0x08, 0xc3, 0x79, 0xa0];
+
+/// The function selector of the builtin `Panic(uint256)` revert reason.
+const PANIC_SELECTOR: [u8; 4] = [0x4e, 0x48, 0x7b, 0x71];
+
+/// Attempts to ABI-decode `output` as a standard `Error(string)` or
+/// `Panic(uint256)` revert payload, returning `(None, None)` for custom
+/// errors or empty reverts.
+fn decode_revert_output(output: &[u8]) -> (Option<String>, Option<BigInt>) {
+    let Some((selector, payload)) = output.split_first_chunk::<4>() else {
+        return (None, None);
+    };
+
+    if *selector == ERROR_SELECTOR {
+        // `offset` (32 bytes) + `length` (32 bytes) + the UTF-8 string
+        let length = payload.get(32..64).map(|bytes| {
+            let mut buffer = [0u8; 8];
+            buffer.copy_from_slice(&bytes[24..32]);
+            u64::from_be_bytes(buffer) as usize
+        });
+
+        let reason = length.and_then(|length| {
+            let end = 64usize.checked_add(length)?;
+            payload
+                .get(64..end)
+                .and_then(|bytes| String::from_utf8(bytes.to_vec()).ok())
+        });
+
+        (reason, None)
+    } else if *selector == PANIC_SELECTOR {
+        let panic_code = payload.get(0..32).map(|bytes| {
+            let mut words = [0u64; 4];
+            for (word, chunk) in words.iter_mut().rev().zip(bytes.chunks_exact(8)) {
+                let mut buffer = [0u8; 8];
+                buffer.copy_from_slice(chunk);
+                *word = u64::from_be_bytes(buffer);
+            }
+
+            BigInt {
+                sign_bit: false,
+                words: words.to_vec(),
+            }
+        });
+
+        (None, panic_code)
+    } else {
+        (None, None)
+    }
+}
+
+/// The specific kind of gas exhaustion that caused an `OutOfGas` halt.
+#[napi]
+pub enum OutOfGasError {
+    /// The basic cost of an opcode exceeded the remaining gas
+    Basic,
+    /// Memory expansion exceeded the remaining gas
+    MemoryLimit,
+    /// Memory expansion cost overflowed
+    Memory,
+    /// A precompile's gas cost exceeded the remaining gas
+    Precompile,
+    /// An invalid operand was supplied to a gas-metered operation (e.g. the
+    /// word count in `MCOPY`)
+    InvalidOperand,
+}
+
+impl From<edr_evm::OutOfGasError> for OutOfGasError {
+    fn from(error: edr_evm::OutOfGasError) -> Self {
+        match error {
+            edr_evm::OutOfGasError::Basic => Self::Basic,
+            edr_evm::OutOfGasError::MemoryLimit => Self::MemoryLimit,
+            edr_evm::OutOfGasError::Memory => Self::Memory,
+            edr_evm::OutOfGasError::Precompile => Self::Precompile,
+            edr_evm::OutOfGasError::InvalidOperand => Self::InvalidOperand,
+        }
+    }
+}
+
+impl From<OutOfGasError> for edr_evm::OutOfGasError {
+    fn from(value: OutOfGasError) -> Self {
+        match value {
+            OutOfGasError::Basic => Self::Basic,
+            OutOfGasError::MemoryLimit => Self::MemoryLimit,
+            OutOfGasError::Memory => Self::Memory,
+            OutOfGasError::Precompile => Self::Precompile,
+            OutOfGasError::InvalidOperand => Self::InvalidOperand,
+        }
+    }
 }
 
 /// Indicates that the EVM has experienced an exceptional halt. This causes
@@ -98,6 +194,16 @@ pub enum ExceptionalHalt {
     CreateContractStartingWithEF,
     /// EIP-3860: Limit and meter initcode. Initcode size limit exceeded.
     CreateInitCodeSizeLimit,
+    /// Overflow in payment
+    OverflowPayment,
+    /// State change during static call
+    StateChangeDuringStaticCall,
+    /// Call not allowed inside static call
+    CallNotAllowedInsideStatic,
+    /// Out of funds to pay for the call
+    OutOfFunds,
+    /// Call is too deep
+    CallTooDeep,
 }
 
 impl From<edr_evm::HaltReason> for ExceptionalHalt {
@@ -123,13 +229,15 @@ impl From<edr_evm::HaltReason> for ExceptionalHalt {
             edr_evm::HaltReason::CreateInitCodeSizeLimit => {
                 ExceptionalHalt::CreateInitCodeSizeLimit
             }
-            edr_evm::HaltReason::OverflowPayment
-            | edr_evm::HaltReason::StateChangeDuringStaticCall
-            | edr_evm::HaltReason::CallNotAllowedInsideStatic
-            | edr_evm::HaltReason::OutOfFunds
-            | edr_evm::HaltReason::CallTooDeep => {
-                unreachable!("Internal halts that can be only found inside Inspector: {halt:?}")
+            edr_evm::HaltReason::OverflowPayment => ExceptionalHalt::OverflowPayment,
+            edr_evm::HaltReason::StateChangeDuringStaticCall => {
+                ExceptionalHalt::StateChangeDuringStaticCall
+            }
+            edr_evm::HaltReason::CallNotAllowedInsideStatic => {
+                ExceptionalHalt::CallNotAllowedInsideStatic
             }
+            edr_evm::HaltReason::OutOfFunds => ExceptionalHalt::OutOfFunds,
+            edr_evm::HaltReason::CallTooDeep => ExceptionalHalt::CallTooDeep,
         }
     }
 }
@@ -151,6 +259,11 @@ impl From<ExceptionalHalt> for edr_evm::HaltReason {
             ExceptionalHalt::CreateContractSizeLimit => Self::CreateContractSizeLimit,
             ExceptionalHalt::CreateContractStartingWithEF => Self::CreateContractStartingWithEF,
             ExceptionalHalt::CreateInitCodeSizeLimit => Self::CreateInitCodeSizeLimit,
+            ExceptionalHalt::OverflowPayment => Self::OverflowPayment,
+            ExceptionalHalt::StateChangeDuringStaticCall => Self::StateChangeDuringStaticCall,
+            ExceptionalHalt::CallNotAllowedInsideStatic => Self::CallNotAllowedInsideStatic,
+            ExceptionalHalt::OutOfFunds => Self::OutOfFunds,
+            ExceptionalHalt::CallTooDeep => Self::CallTooDeep,
         }
     }
 }
@@ -160,6 +273,13 @@ impl From<ExceptionalHalt> for edr_evm::HaltReason {
 pub struct HaltResult {
     /// The exceptional halt that occurred
     pub reason: ExceptionalHalt,
+    /// The specific kind of gas exhaustion, if `reason` is `OutOfGas`
+    pub out_of_gas_error: Option<OutOfGasError>,
+    /// The offending opcode byte, if `reason` is `InvalidFEOpcode` or
+    /// `CreateContractStartingWithEF`. Not populated for `OpcodeNotFound`,
+    /// since `edr_evm::HaltReason` doesn't carry the triggering byte for
+    /// that variant.
+    pub opcode: Option<u8>,
     /// Halting will spend all the gas and will thus be equal to the specified
     /// gas limit
     pub gas_used: BigInt,
@@ -231,6 +351,7 @@ impl ExecutionResult {
                 })
             }
             edr_evm::ExecutionResult::Revert { gas_used, output } => {
+                let (reason, panic_code) = decode_revert_output(output);
                 let output = output.clone();
                 Either3::B(RevertResult {
                     gas_used: BigInt::from(*gas_used),
@@ -245,10 +366,28 @@ impl ExecutionResult {
                         )
                     }
                     .map(JsBufferValue::into_raw)?,
+                    reason,
+                    panic_code,
                 })
             }
             edr_evm::ExecutionResult::Halt { reason, gas_used } => Either3::C(HaltResult {
                 reason: ExceptionalHalt::from(*reason),
+                out_of_gas_error: match reason {
+                    edr_evm::HaltReason::OutOfGas(error) => Some(OutOfGasError::from(*error)),
+                    _ => None,
+                },
+                opcode: match reason {
+                    // EIP-3541 forbids new contract code starting with the `0xEF` byte, so the
+                    // offending byte is always `0xEF` by definition of the check.
+                    edr_evm::HaltReason::CreateContractStartingWithEF => Some(0xEF),
+                    // `0xFE` is the dedicated `INVALID` opcode, so the byte is fixed by
+                    // definition of the variant.
+                    edr_evm::HaltReason::InvalidFEOpcode => Some(0xFE),
+                    // `edr_evm::HaltReason` doesn't carry the triggering opcode byte for this
+                    // variant, so it can't be recovered here.
+                    edr_evm::HaltReason::OpcodeNotFound => None,
+                    _ => None,
+                },
                 gas_used: BigInt::from(*gas_used),
             }),
         };